@@ -8,17 +8,38 @@ pub mod features;
 
 #[cfg(feature = "compact")]
 use compact_str::CompactString;
+
+/// The case-sensitivity mode carried by a [`CaseInsensitiveString`].
+///
+/// Borrowed from the `Case` design in the cdx text utilities: most values
+/// stay case-insensitive (the crate's namesake behavior), but a single
+/// instance can opt into exact, case-sensitive comparisons via
+/// [`CaseInsensitiveString::with_case`] instead of requiring a second,
+/// unrelated key type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Case {
+    /// Compare, order and hash with full case sensitivity (exact match).
+    Sens,
+    /// Compare, order and hash ignoring case. The default.
+    #[default]
+    Insens,
+}
+
 /// case-insensitive string handling
 #[cfg(not(feature = "compact"))]
 #[derive(Debug, Clone, Default)]
-#[repr(transparent)]
-pub struct CaseInsensitiveString(String);
+pub struct CaseInsensitiveString {
+    inner: String,
+    case: Case,
+}
 
 /// case-insensitive string handling
 #[cfg(feature = "compact")]
 #[derive(Debug, Clone, Default)]
-#[repr(transparent)]
-pub struct CaseInsensitiveString(CompactString);
+pub struct CaseInsensitiveString {
+    inner: CompactString,
+    case: Case,
+}
 
 impl CaseInsensitiveString {
     /// Creates a `CaseInsensitiveString` slice from any byte slice.
@@ -43,21 +64,55 @@ impl CaseInsensitiveString {
         CaseInsensitiveString::from(bytes.as_ref())
     }
 
+    /// Creates a `CaseInsensitiveString` from any byte slice with an explicit
+    /// [`Case`] mode, instead of the default case-insensitive behavior.
+    ///
+    /// # Example
+    /// ```
+    /// use case_insensitive_string::{Case, CaseInsensitiveString};
+    ///
+    /// let sensitive = CaseInsensitiveString::with_case(b"Foo", Case::Sens);
+    ///
+    /// assert_ne!(sensitive, CaseInsensitiveString::new("foo"));
+    ///
+    /// // Comparisons are symmetric regardless of which side is `Case::Sens`:
+    /// // a `Case::Sens` value never equals a `Case::Insens` one, even when
+    /// // their bytes match exactly.
+    /// assert_ne!(CaseInsensitiveString::new("foo"), sensitive);
+    /// assert_eq!(sensitive.cmp(&CaseInsensitiveString::new("foo")).is_eq(), false);
+    /// assert_eq!(CaseInsensitiveString::new("foo").cmp(&sensitive).is_eq(), false);
+    /// ```
+    #[inline]
+    pub fn with_case<'a, B: ?Sized + AsRef<[u8]>>(
+        bytes: &'a B,
+        case: Case,
+    ) -> CaseInsensitiveString {
+        let mut s = CaseInsensitiveString::from(bytes.as_ref());
+        s.case = case;
+        s
+    }
+
+    /// Returns the [`Case`] mode this value compares, orders and hashes with.
+    #[inline]
+    pub fn case(&self) -> Case {
+        self.case
+    }
+
     #[inline]
     pub fn as_bytes(&self) -> &[u8] {
-        &self.0.as_bytes()
+        &self.inner.as_bytes()
     }
 
     #[cfg(not(feature = "compact"))]
     #[inline]
     pub fn inner(&self) -> &String {
-        &self.0
+        &self.inner
     }
 
     #[cfg(feature = "compact")]
     #[inline]
     pub fn inner(&self) -> &CompactString {
-        &self.0
+        &self.inner
     }
 
     /// Appends the given [`char`] to the end of this [`CaseInsensitiveString`].
@@ -90,13 +145,13 @@ impl CaseInsensitiveString {
     /// ```
     #[inline]
     pub fn push_str(&mut self, s: &str) {
-        self.0.push_str(s)
+        self.inner.push_str(s)
     }
 
     /// Convert the [`CaseInsensitiveString`] into a [`String`].
     /// ```
     pub fn into_string(self) -> String {
-        self.0.into()
+        self.inner.into()
     }
 
     /// Removes a [`char`] from this [`CaseInsensitiveString`] at a byte position and returns it.
@@ -141,7 +196,7 @@ impl CaseInsensitiveString {
     /// ```
     #[inline]
     pub fn remove(&mut self, idx: usize) -> char {
-        self.0.remove(idx)
+        self.inner.remove(idx)
     }
 
     /// Returns the length of the [`CaseInsensitiveString`] in `bytes`, not [`char`]s or graphemes.
@@ -161,7 +216,7 @@ impl CaseInsensitiveString {
     /// ```
     #[inline]
     pub fn len(&self) -> usize {
-        self.0.len()
+        self.inner.len()
     }
 
     /// Returns `true` if the [`CaseInsensitiveString`] has a length of 0, `false` otherwise
@@ -180,21 +235,554 @@ impl CaseInsensitiveString {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Returns `true` if `pat` occurs anywhere in this string, honoring this
+    /// value's [`Case`] mode (case-insensitive by default).
+    ///
+    /// # Examples
+    /// ```
+    /// # use case_insensitive_string::CaseInsensitiveString;
+    /// let s = CaseInsensitiveString::new("Hello, World!");
+    /// assert!(s.contains("world"));
+    /// assert!(!s.contains("mars"));
+    /// ```
+    #[inline]
+    pub fn contains(&self, pat: &str) -> bool {
+        self.find(pat).is_some()
+    }
+
+    /// Returns `true` if this string starts with `pat`, honoring this
+    /// value's [`Case`] mode (case-insensitive by default).
+    ///
+    /// # Examples
+    /// ```
+    /// # use case_insensitive_string::CaseInsensitiveString;
+    /// let s = CaseInsensitiveString::new("Hello, World!");
+    /// assert!(s.starts_with("HELLO"));
+    /// ```
+    ///
+    /// A [`Case::Sens`] instance requires an exact match, just like its
+    /// `PartialEq` impl:
+    /// ```
+    /// use case_insensitive_string::{Case, CaseInsensitiveString};
+    ///
+    /// let sensitive = CaseInsensitiveString::with_case(b"Admin", Case::Sens);
+    /// assert!(!sensitive.starts_with("ADMIN"));
+    /// assert!(!sensitive.contains("admin"));
+    /// assert_eq!(sensitive.find("ADMIN"), None);
+    /// assert!(sensitive.starts_with("Admin"));
+    /// ```
+    #[inline]
+    pub fn starts_with(&self, pat: &str) -> bool {
+        match_prefix_len(&self.inner, pat, self.case).is_some()
+    }
+
+    /// Returns `true` if this string ends with `pat`, honoring this value's
+    /// [`Case`] mode (case-insensitive by default).
+    ///
+    /// # Examples
+    /// ```
+    /// # use case_insensitive_string::CaseInsensitiveString;
+    /// let s = CaseInsensitiveString::new("Hello, World!");
+    /// assert!(s.ends_with("WORLD!"));
+    /// ```
+    pub fn ends_with(&self, pat: &str) -> bool {
+        if self.case == Case::Sens {
+            return self.inner.ends_with(pat);
+        }
+        let mut a = fold_chars(&self.inner).rev();
+        let mut b = fold_chars(pat).rev();
+        loop {
+            match (a.next(), b.next()) {
+                (Some(x), Some(y)) if x == y => continue,
+                (_, None) => return true,
+                _ => return false,
+            }
+        }
+    }
+
+    /// Returns the byte index of the first match of `pat`, honoring this
+    /// value's [`Case`] mode (case-insensitive by default), or `None` if it
+    /// doesn't occur.
+    ///
+    /// # Examples
+    /// ```
+    /// # use case_insensitive_string::CaseInsensitiveString;
+    /// let s = CaseInsensitiveString::new("Hello, World!");
+    /// assert_eq!(s.find("world"), Some(7));
+    /// assert_eq!(s.find("mars"), None);
+    /// ```
+    pub fn find(&self, pat: &str) -> Option<usize> {
+        if pat.is_empty() {
+            return Some(0);
+        }
+        for (start, _) in self.inner.char_indices() {
+            if match_prefix_len(&self.inner[start..], pat, self.case).is_some() {
+                return Some(start);
+            }
+        }
+        None
+    }
+
+    /// Returns the byte index of the last match of `pat`, honoring this
+    /// value's [`Case`] mode (case-insensitive by default), or `None` if it
+    /// doesn't occur.
+    ///
+    /// # Examples
+    /// ```
+    /// # use case_insensitive_string::CaseInsensitiveString;
+    /// let s = CaseInsensitiveString::new("Admin/admin/ADMIN");
+    /// assert_eq!(s.rfind("admin"), Some(12));
+    /// ```
+    pub fn rfind(&self, pat: &str) -> Option<usize> {
+        if pat.is_empty() {
+            return Some(self.inner.len());
+        }
+        let mut last = None;
+        for (start, _) in self.inner.char_indices() {
+            if match_prefix_len(&self.inner[start..], pat, self.case).is_some() {
+                last = Some(start);
+            }
+        }
+        last
+    }
+
+    /// Splits this string on each non-overlapping match of `pat`, honoring
+    /// this value's [`Case`] mode (case-insensitive by default), yielding the
+    /// pieces in their original casing.
+    ///
+    /// # Examples
+    /// ```
+    /// # use case_insensitive_string::CaseInsensitiveString;
+    /// let s = CaseInsensitiveString::new("a-B-c");
+    /// let parts: Vec<&str> = s.split("-").collect();
+    /// assert_eq!(parts, vec!["a", "B", "c"]);
+    /// ```
+    #[inline]
+    pub fn split<'a>(&'a self, pat: &'a str) -> CaseInsensitiveSplit<'a> {
+        CaseInsensitiveSplit {
+            haystack: &self.inner,
+            pat,
+            case: self.case,
+            finished: false,
+        }
+    }
+
+    /// Replaces all matches of `pat` with `replacement`, honoring this
+    /// value's [`Case`] mode (case-insensitive by default); the replacement
+    /// is substituted verbatim (it is not itself case-folded).
+    ///
+    /// # Examples
+    /// ```
+    /// # use case_insensitive_string::CaseInsensitiveString;
+    /// let s = CaseInsensitiveString::new("Admin panel for admin");
+    /// assert_eq!(s.replace("admin", "user"), "user panel for user");
+    /// ```
+    ///
+    /// A [`Case::Sens`] instance only replaces exact matches:
+    /// ```
+    /// use case_insensitive_string::{Case, CaseInsensitiveString};
+    ///
+    /// let s = CaseInsensitiveString::with_case(b"Admin panel for admin", Case::Sens);
+    /// assert_eq!(s.replace("admin", "user"), "Admin panel for user");
+    /// ```
+    #[inline]
+    pub fn replace(&self, pat: &str, replacement: &str) -> String {
+        self.replacen(pat, replacement, usize::MAX)
+    }
+
+    /// Replaces the first `count` matches of `pat` with `replacement`,
+    /// honoring this value's [`Case`] mode (case-insensitive by default) and
+    /// substituting the replacement verbatim.
+    ///
+    /// # Examples
+    /// ```
+    /// # use case_insensitive_string::CaseInsensitiveString;
+    /// let s = CaseInsensitiveString::new("Admin panel for admin");
+    /// assert_eq!(s.replacen("admin", "user", 1), "user panel for admin");
+    /// ```
+    pub fn replacen(&self, pat: &str, replacement: &str, count: usize) -> String {
+        let mut haystack = self.inner.as_str();
+
+        if pat.is_empty() || count == 0 {
+            return haystack.to_string();
+        }
+
+        let mut result = String::with_capacity(self.inner.len());
+        let mut remaining = count;
+
+        while remaining > 0 {
+            let mut matched = false;
+            for (start, _) in haystack.char_indices() {
+                if let Some(len) = match_prefix_len(&haystack[start..], pat, self.case) {
+                    result.push_str(&haystack[..start]);
+                    result.push_str(replacement);
+                    haystack = &haystack[start + len..];
+                    remaining -= 1;
+                    matched = true;
+                    break;
+                }
+            }
+            if !matched {
+                break;
+            }
+        }
+
+        result.push_str(haystack);
+        result
+    }
+}
+
+/// Iterator over substrings of a [`CaseInsensitiveString`] separated by a
+/// case-insensitive delimiter, created by [`CaseInsensitiveString::split`].
+pub struct CaseInsensitiveSplit<'a> {
+    haystack: &'a str,
+    pat: &'a str,
+    case: Case,
+    finished: bool,
+}
+
+impl<'a> Iterator for CaseInsensitiveSplit<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        if self.finished {
+            return None;
+        }
+        if self.pat.is_empty() {
+            self.finished = true;
+            return Some(self.haystack);
+        }
+        for (start, _) in self.haystack.char_indices() {
+            if let Some(len) = match_prefix_len(&self.haystack[start..], self.pat, self.case) {
+                let piece = &self.haystack[..start];
+                self.haystack = &self.haystack[start + len..];
+                return Some(piece);
+            }
+        }
+        self.finished = true;
+        Some(self.haystack)
+    }
+}
+
+/// Folds a single [`char`] to its lowercase mapping.
+///
+/// Under the `unicode` feature this maps each `char` through
+/// [`char::to_lowercase`], which is not full case folding: `ß` stays `ß`
+/// rather than becoming `ss`, and final sigma `ς` stays `ς` rather than
+/// becoming `σ`. The lowercase mapping can still expand to more than one
+/// `char` (`İ`, U+0130, lowercases to `i` followed by a combining dot above);
+/// the streaming [`Hash`](std::hash::Hash) invariant (`a == b` implies
+/// `hash(a) == hash(b)`) holds regardless, because [`PartialEq`] and `Hash`
+/// both walk the identical [`fold_chars`] stream and so see the same
+/// expansion on both sides. Otherwise (without `unicode`) it folds ASCII
+/// letters only, matching `eq_ignore_ascii_case`.
+#[cfg(feature = "unicode")]
+#[inline]
+fn fold_char(c: char) -> std::char::ToLowercase {
+    c.to_lowercase()
+}
+
+#[cfg(not(feature = "unicode"))]
+#[inline]
+fn fold_char(c: char) -> std::iter::Once<char> {
+    std::iter::once(c.to_ascii_lowercase())
+}
+
+/// Lazily folds a string's [`char`]s to their lowercase mapping for
+/// case-insensitive comparison, ordering, hashing and searching, without
+/// allocating an intermediate lowercased copy.
+#[inline]
+fn fold_chars(s: &str) -> impl DoubleEndedIterator<Item = char> + '_ {
+    s.chars().flat_map(fold_char)
+}
+
+/// Compares two strings char-by-char under case folding, without allocating
+/// an intermediate lowercased copy.
+#[inline]
+fn fold_eq(a: &str, b: &str) -> bool {
+    let mut x = fold_chars(a);
+    let mut y = fold_chars(b);
+    loop {
+        match (x.next(), y.next()) {
+            (Some(p), Some(q)) if p == q => continue,
+            (None, None) => return true,
+            _ => return false,
+        }
+    }
+}
+
+/// Orders two strings char-by-char under case folding, without allocating
+/// an intermediate lowercased copy.
+fn fold_cmp(a: &str, b: &str) -> std::cmp::Ordering {
+    let mut x = fold_chars(a);
+    let mut y = fold_chars(b);
+    loop {
+        return match (x.next(), y.next()) {
+            (Some(p), Some(q)) => match p.cmp(&q) {
+                std::cmp::Ordering::Equal => continue,
+                ord => ord,
+            },
+            (Some(_), None) => std::cmp::Ordering::Greater,
+            (None, Some(_)) => std::cmp::Ordering::Less,
+            (None, None) => std::cmp::Ordering::Equal,
+        };
+    }
+}
+
+/// Compares two strings according to `case`: an exact byte comparison for
+/// [`Case::Sens`], or a case-folded comparison otherwise. Used by the
+/// cross-type `PartialEq` impls (`str`, `String`, `Cow`, ...) so they honor
+/// the other side's [`Case`] mode and folding rules instead of always
+/// falling back to ASCII case-insensitive comparison.
+#[inline]
+fn mode_eq(case: Case, a: &str, b: &str) -> bool {
+    match case {
+        Case::Sens => a == b,
+        Case::Insens => fold_eq(a, b),
+    }
+}
+
+/// Finds the byte length, within `haystack`, of a match of `pat` anchored at
+/// the start of `haystack`, honoring `case`: an exact byte-prefix match for
+/// [`Case::Sens`], or a case-folded match otherwise.
+///
+/// The case-folded branch walks `haystack` one [`char`] at a time so the
+/// returned length always lands on a `char` boundary, even when folding a
+/// single haystack char expands to more than one folded char.
+fn match_prefix_len(haystack: &str, pat: &str, case: Case) -> Option<usize> {
+    if pat.is_empty() {
+        return Some(0);
+    }
+
+    if case == Case::Sens {
+        return haystack.starts_with(pat).then_some(pat.len());
+    }
+
+    let mut pat_chars = fold_chars(pat).peekable();
+
+    for (offset, c) in haystack.char_indices() {
+        for fc in fold_char(c) {
+            match pat_chars.next() {
+                Some(pc) if pc == fc => {}
+                _ => return None,
+            }
+        }
+        if pat_chars.peek().is_none() {
+            return Some(offset + c.len_utf8());
+        }
+    }
+
+    None
 }
 
 impl Eq for CaseInsensitiveString {}
 
+/// Always hashes the case-folded form, regardless of this value's own
+/// [`Case`] mode. [`PartialEq`] only ever returns `true` when its folded form
+/// would also match (see its impl below — mixed-mode pairs are always
+/// unequal, and same-mode pairs either require folded equality or are a
+/// special case of it), so two values that compare equal always agree on
+/// their folded form; hashing the folded form unconditionally keeps the
+/// `Hash`/`Eq` contract sound across mixed modes (a `Case::Sens` value
+/// simply collides, harmlessly, with any differently-cased or
+/// differently-moded value it happens to be unequal to).
 impl std::hash::Hash for CaseInsensitiveString {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        for c in fold_chars(&self.inner) {
+            c.hash(state);
+        }
+    }
+}
+
+impl PartialOrd for CaseInsensitiveString {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders case-insensitively by default, so `CaseInsensitiveString` can be
+/// used as a `BTreeMap`/`BTreeSet` key without allocating lowercased
+/// copies to compare.
+///
+/// # Example
+/// ```
+/// use case_insensitive_string::CaseInsensitiveString;
+/// use std::collections::BTreeSet;
+///
+/// let mut set = BTreeSet::new();
+/// set.insert(CaseInsensitiveString::from("Banana"));
+/// set.insert(CaseInsensitiveString::from("apple"));
+/// set.insert(CaseInsensitiveString::from("APPLE"));
+///
+/// // "apple" and "APPLE" are the same key, so only two entries remain,
+/// // ordered case-insensitively.
+/// let fruits: Vec<String> = set.iter().map(ToString::to_string).collect();
+/// assert_eq!(fruits, vec!["apple".to_string(), "Banana".to_string()]);
+/// ```
+///
+/// Mixing a [`Case::Sens`] value in with [`Case::Insens`] ones still orders
+/// consistently, so the two fold-equal `Case::Insens` entries still dedupe:
+/// ```
+/// use case_insensitive_string::{Case, CaseInsensitiveString};
+/// use std::collections::BTreeSet;
+///
+/// let mut set = BTreeSet::new();
+/// set.insert(CaseInsensitiveString::with_case(b"Foo", Case::Sens));
+/// set.insert(CaseInsensitiveString::from("foo"));
+/// set.insert(CaseInsensitiveString::from("FOO"));
+/// assert_eq!(set.len(), 2);
+/// ```
+impl Ord for CaseInsensitiveString {
+    /// Orders primarily by case-folded text — so same-fold values still sort
+    /// and cluster together regardless of mode — and only consults [`Case`]
+    /// to break a tie between same-fold values, exactly mirroring
+    /// [`PartialEq`] below so `a.cmp(&b) == Equal` iff `a == b`:
+    /// [`Case::Insens`] values never need a tiebreak once folds match,
+    /// [`Case::Sens`] values tiebreak by their exact bytes, and a
+    /// [`Case::Sens`]/[`Case::Insens`] pair that folds equal always resolves
+    /// to the `Case::Sens` side sorting first (an arbitrary but fixed and
+    /// total rule).
+    ///
+    /// Ordering by *either* side being [`Case::Sens`] (as [`PartialEq`] used
+    /// to) is not transitive: with `a = with_case(b"Foo", Sens)`,
+    /// `b = new("foo")`, `c = new("FOO")`, that rule gives `a < b`, `b == c`
+    /// (both `Case::Insens`, fold-equal), yet `a > c`, corrupting
+    /// `BTreeSet`/`BTreeMap` invariants for any collection mixing modes.
+    /// Deciding the tie from each side's *own* mode instead — never from the
+    /// pairing — keeps the order total.
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let folded = fold_cmp(&self.inner, &other.inner);
+        if folded != std::cmp::Ordering::Equal {
+            return folded;
+        }
+
+        match (self.case, other.case) {
+            (Case::Insens, Case::Insens) => std::cmp::Ordering::Equal,
+            (Case::Sens, Case::Sens) => self.inner.as_str().cmp(other.inner.as_str()),
+            (Case::Sens, Case::Insens) => std::cmp::Ordering::Less,
+            (Case::Insens, Case::Sens) => std::cmp::Ordering::Greater,
+        }
+    }
+}
+
+/// A borrowed, always-case-folding string slice.
+///
+/// Mirrors [`CaseInsensitiveString`] the way [`str`] mirrors [`String`]: an
+/// unsized, `#[repr(transparent)]` wrapper over [`str`] that carries
+/// case-insensitive [`PartialEq`], [`Hash`] and [`Ord`] impls, with no
+/// allocation needed to build one from an existing `&str`.
+///
+/// This does *not* give `HashMap<CaseInsensitiveString, _>` an
+/// allocation-free `.get(&str)` / `.get(CaseInsensitiveStr::new(..))` lookup —
+/// that was the original motivation for this type, but it isn't
+/// deliverable as specified. [`CaseInsensitiveString`] is paired with no
+/// [`Borrow`](std::borrow::Borrow) impl at all, not even `Borrow<str>`; see
+/// the note below and the one above its (removed) `Borrow<str>` impl for
+/// why. As it stands, `CaseInsensitiveStr` is a standalone comparable slice:
+/// useful for comparing or sorting two slices without allocating, not for
+/// map lookups.
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct CaseInsensitiveStr(str);
+
+impl CaseInsensitiveStr {
+    /// Creates a `&CaseInsensitiveStr` from a `&str`, with no allocation.
+    ///
+    /// # Example
+    /// ```
+    /// use case_insensitive_string::CaseInsensitiveStr;
+    ///
+    /// let a = CaseInsensitiveStr::new("abc");
+    /// let b = CaseInsensitiveStr::new("ABC");
+    ///
+    /// assert_eq!(a, b);
+    /// ```
+    #[inline]
+    pub fn new(s: &str) -> &CaseInsensitiveStr {
+        // SAFETY: `CaseInsensitiveStr` is `#[repr(transparent)]` over `str`,
+        // so a `&str` and a `&CaseInsensitiveStr` share the same layout and
+        // this pointer cast is valid.
+        unsafe { &*(s as *const str as *const CaseInsensitiveStr) }
+    }
+}
+
+impl std::ops::Deref for CaseInsensitiveStr {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for CaseInsensitiveStr {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Eq for CaseInsensitiveStr {}
+
+impl PartialEq for CaseInsensitiveStr {
+    fn eq(&self, other: &Self) -> bool {
+        fold_eq(&self.0, &other.0)
+    }
+}
+
+impl std::hash::Hash for CaseInsensitiveStr {
     #[inline]
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        self.0.to_ascii_lowercase().hash(state)
+        for c in fold_chars(&self.0) {
+            c.hash(state);
+        }
+    }
+}
+
+impl PartialOrd for CaseInsensitiveStr {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CaseInsensitiveStr {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        fold_cmp(&self.0, &other.0)
     }
 }
 
+// `CaseInsensitiveStr` intentionally does *not* get a
+// `Borrow<CaseInsensitiveStr>` impl on `CaseInsensitiveString`, even though
+// that would let a `HashMap<CaseInsensitiveString, _>` be queried by an
+// existing `&str` without allocating.
+//
+// `Borrow`'s contract requires `x == y` to be equivalent to
+// `x.borrow() == y.borrow()` for *every* pair the collection might ever
+// hold. `CaseInsensitiveStr` carries no `Case` mode of its own and always
+// folds case, while `CaseInsensitiveString`'s own `Eq` partitions by each
+// side's own `Case` mode (see its impl below) so that, for example, two
+// `Case::Sens` values like `"Admin"` and `"ADMIN"` are legitimately distinct
+// keys. Folding away that distinction once borrowed means two keys that are
+// `!=` as `CaseInsensitiveString` would compare `==` as `CaseInsensitiveStr`,
+// breaking the contract for any collection that mixes modes or holds more
+// than one `Case::Sens` key per folded text. Since `Case` is a runtime field
+// rather than something the type system can rule out per-instance, there is
+// no way to implement `Borrow` here that is sound for every value of
+// `CaseInsensitiveString` — so it isn't implemented. Build a
+// `&CaseInsensitiveStr` with [`CaseInsensitiveStr::new`] directly (e.g. to
+// compare two slices, or to look a key up by hand) when you know a
+// collection holds only `Case::Insens` keys.
+
 impl From<&str> for CaseInsensitiveString {
     #[inline]
     fn from(s: &str) -> Self {
-        CaseInsensitiveString { 0: s.into() }
+        CaseInsensitiveString {
+            inner: s.into(),
+            case: Case::default(),
+        }
     }
 }
 
@@ -202,20 +790,27 @@ impl From<&str> for CaseInsensitiveString {
 impl From<CompactString> for CaseInsensitiveString {
     #[inline]
     fn from(s: CompactString) -> Self {
-        CaseInsensitiveString { 0: s.into() }
+        CaseInsensitiveString {
+            inner: s.into(),
+            case: Case::default(),
+        }
     }
 }
 
 impl From<String> for CaseInsensitiveString {
     fn from(s: String) -> Self {
-        CaseInsensitiveString { 0: s.into() }
+        CaseInsensitiveString {
+            inner: s.into(),
+            case: Case::default(),
+        }
     }
 }
 
 impl From<&[u8]> for CaseInsensitiveString {
     fn from(s: &[u8]) -> Self {
         CaseInsensitiveString {
-            0: String::from_utf8_lossy(s).into(),
+            inner: String::from_utf8_lossy(s).into(),
+            case: Case::default(),
         }
     }
 }
@@ -230,7 +825,7 @@ impl From<CaseInsensitiveString> for String {
 impl From<&CaseInsensitiveString> for String {
     #[inline]
     fn from(s: &CaseInsensitiveString) -> Self {
-        s.0.to_string()
+        s.inner.to_string()
     }
 }
 
@@ -241,16 +836,64 @@ impl From<&CaseInsensitiveString> for CaseInsensitiveString {
     }
 }
 
+/// Serializes as a plain string, in its original casing.
+#[cfg(feature = "serde")]
+impl serde::Serialize for CaseInsensitiveString {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.inner.as_str())
+    }
+}
+
+/// Deserializes from a plain string through the same [`From<String>`] path
+/// (or, under the `compact` feature, the same [`From<CompactString>`] path)
+/// used everywhere else in the crate, so a value serialized from `"ABC"`
+/// deserializes back to something that still compares equal (and hashes
+/// equal) to `"abc"` under the default [`Case::Insens`] mode.
+///
+/// This is lossy for a [`Case::Sens`] value: the wire format is a plain
+/// string with no room for the mode, so the value is always reconstructed
+/// with the default `Case::Insens`. Round-tripping a `Case::Sens` value
+/// changes its comparison semantics — serialize it yourself alongside an
+/// explicit mode if that distinction must survive the trip.
+///
+/// Building with both `compact` and `serde` enabled requires `Cargo.toml` to
+/// also turn on `compact_str`'s own `serde` feature (e.g. via
+/// `serde = ["dep:serde", "compact_str?/serde"]`) — `CompactString`'s
+/// `Deserialize` impl is gated behind it upstream, and nothing below this
+/// comment can enable it from inside `lib.rs`.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CaseInsensitiveString {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[cfg(feature = "compact")]
+        {
+            <CompactString as serde::Deserialize>::deserialize(deserializer)
+                .map(CaseInsensitiveString::from)
+        }
+        #[cfg(not(feature = "compact"))]
+        {
+            <String as serde::Deserialize>::deserialize(deserializer).map(CaseInsensitiveString::from)
+        }
+    }
+}
+
 impl AsRef<str> for CaseInsensitiveString {
     #[inline]
     fn as_ref(&self) -> &str {
-        &self.0
+        &self.inner
     }
 }
 
 impl core::fmt::Display for CaseInsensitiveString {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}", self.inner)
     }
 }
 
@@ -259,100 +902,133 @@ impl std::ops::Deref for CaseInsensitiveString {
 
     #[inline]
     fn deref(&self) -> &str {
-        &self.0.as_str()
+        &self.inner.as_str()
     }
 }
 
 impl std::ops::DerefMut for CaseInsensitiveString {
     #[inline]
     fn deref_mut(&mut self) -> &mut str {
-        self.0.as_mut_str()
+        self.inner.as_mut_str()
     }
 }
 
-impl std::borrow::Borrow<str> for CaseInsensitiveString {
-    #[inline]
-    fn borrow(&self) -> &str {
-        &self.0.as_str()
-    }
-}
+// No `Borrow<str>` (nor `Borrow<CaseInsensitiveStr>`, see the note above) is
+// implemented for `CaseInsensitiveString`. An earlier version of this crate
+// had one, but it was already unsound before `Case` existed: `str`'s
+// `Eq`/`Hash` are exact-byte, while `CaseInsensitiveString`'s have always
+// folded case, so `CaseInsensitiveString::from("foo") ==
+// CaseInsensitiveString::from("FOO")` while `"foo" != "FOO"` as `str` —
+// violating `Borrow`'s `x == y ⟺ x.borrow() == y.borrow()` requirement the
+// moment a collection holds more than one casing of the same text.
+// [`Case::Sens`] doesn't cause this; it just adds a second, independent way
+// to break the same contract: a `Case::Sens` value and a `Case::Insens`
+// value sharing the same bytes (e.g. `with_case(b"abc", Sens)` and
+// `new("abc")`) always compare unequal as `CaseInsensitiveString` (see its
+// `PartialEq` impl below) yet compare equal as `str` once borrowed, since
+// `Borrow<str>` can't see `Case` at all. Either violation alone rules the
+// impl out, and dropping `Case::Sens` would not bring it back: the original
+// exact-byte-vs-folded mismatch predates it and isn't tied to a runtime
+// field the type system could rule out. Use [`CaseInsensitiveString::from`]
+// (it allocates) or iterate and compare with `==` in the meantime.
 
 impl PartialEq for CaseInsensitiveString {
-    #[inline]
+    /// Compares exactly when *both* sides are [`Case::Sens`], folds case when
+    /// *both* sides are [`Case::Insens`], and is always unequal when the
+    /// sides' modes differ.
+    ///
+    /// A [`Case::Sens`] value and a [`Case::Insens`] value that happen to
+    /// share the same bytes are deliberately never equal: letting matching
+    /// bytes make them equal (as an "either side" rule would) breaks `Eq`'s
+    /// transitivity as soon as a third, differently-cased `Case::Insens`
+    /// value enters the picture — it would fold-equal the `Case::Insens` side
+    /// without fold-equaling the `Case::Sens` side, so `a == b && b == c`
+    /// would no longer imply `a == c`. Partitioning by mode first keeps `eq`
+    /// (and the matching [`Ord`] impl above) a genuine equivalence/total
+    /// order even when a collection mixes modes.
     fn eq(&self, other: &Self) -> bool {
-        self.0.eq_ignore_ascii_case(&other.0)
+        match (self.case, other.case) {
+            (Case::Sens, Case::Sens) => self.inner.as_str() == other.inner.as_str(),
+            (Case::Insens, Case::Insens) => fold_eq(&self.inner, &other.inner),
+            _ => false,
+        }
     }
 }
 
+// The cross-type impls below route through `mode_eq` so a plain `str`/
+// `String`/`Cow` comparison honors the `CaseInsensitiveString` side's
+// `Case` mode and folding rules (including the `unicode` feature) instead
+// of always falling back to ASCII case-insensitive comparison.
+
 #[cfg(feature = "compact")]
 impl PartialEq<CaseInsensitiveString> for &CompactString {
     fn eq(&self, other: &CaseInsensitiveString) -> bool {
-        self.eq_ignore_ascii_case(&other.as_ref())
+        mode_eq(other.case, self.as_ref(), other.as_ref())
     }
 }
 
 impl PartialEq<CaseInsensitiveString> for String {
     fn eq(&self, other: &CaseInsensitiveString) -> bool {
-        self.eq_ignore_ascii_case(&other.as_ref())
+        mode_eq(other.case, self.as_ref(), other.as_ref())
     }
 }
 
 impl<'a> PartialEq<&'a CaseInsensitiveString> for String {
     fn eq(&self, other: &&CaseInsensitiveString) -> bool {
-        self.eq_ignore_ascii_case(&other.as_ref())
+        mode_eq(other.case, self.as_ref(), other.as_ref())
     }
 }
 
 impl PartialEq<CaseInsensitiveString> for &String {
     fn eq(&self, other: &CaseInsensitiveString) -> bool {
-        self.eq_ignore_ascii_case(&other.as_ref())
+        mode_eq(other.case, self.as_ref(), other.as_ref())
     }
 }
 
 impl PartialEq<CaseInsensitiveString> for str {
     fn eq(&self, other: &CaseInsensitiveString) -> bool {
-        self.eq_ignore_ascii_case(&other.as_ref())
+        mode_eq(other.case, self, other.as_ref())
     }
 }
 
 impl<'a> PartialEq<&'a CaseInsensitiveString> for str {
     fn eq(&self, other: &&CaseInsensitiveString) -> bool {
-        self.eq_ignore_ascii_case(&other.as_ref())
+        mode_eq(other.case, self, other.as_ref())
     }
 }
 
 impl PartialEq<CaseInsensitiveString> for &str {
     fn eq(&self, other: &CaseInsensitiveString) -> bool {
-        self.eq_ignore_ascii_case(&other.as_ref())
+        mode_eq(other.case, self, other.as_ref())
     }
 }
 
 impl PartialEq<CaseInsensitiveString> for &&str {
     fn eq(&self, other: &CaseInsensitiveString) -> bool {
-        self.eq_ignore_ascii_case(&other.as_ref())
+        mode_eq(other.case, self, other.as_ref())
     }
 }
 
 impl<'a> PartialEq<CaseInsensitiveString> for std::borrow::Cow<'a, str> {
     fn eq(&self, other: &CaseInsensitiveString) -> bool {
-        self.eq_ignore_ascii_case(&other.as_ref())
+        mode_eq(other.case, self.as_ref(), other.as_ref())
     }
 }
 
 impl<'a> PartialEq<CaseInsensitiveString> for &std::borrow::Cow<'a, str> {
     fn eq(&self, other: &CaseInsensitiveString) -> bool {
-        self.eq_ignore_ascii_case(&other.as_ref())
+        mode_eq(other.case, self.as_ref(), other.as_ref())
     }
 }
 
 impl PartialEq<String> for &CaseInsensitiveString {
     fn eq(&self, other: &String) -> bool {
-        self.eq_ignore_ascii_case(&other.as_ref())
+        mode_eq(self.case, self.as_ref(), other.as_ref())
     }
 }
 
 impl<'a> PartialEq<std::borrow::Cow<'a, str>> for &CaseInsensitiveString {
     fn eq(&self, other: &std::borrow::Cow<'a, str>) -> bool {
-        self.eq_ignore_ascii_case(&other.as_ref())
+        mode_eq(self.case, self.as_ref(), other.as_ref())
     }
 }
@@ -0,0 +1,41 @@
+#[cfg(feature = "serde")]
+mod tests {
+    use case_insensitive_string::{Case, CaseInsensitiveString};
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    fn hash_of(s: &CaseInsensitiveString) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        s.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn round_trips_ignoring_case() {
+        let original = CaseInsensitiveString::from("ABC");
+        let json = serde_json::to_string(&original).unwrap();
+        let restored: CaseInsensitiveString = serde_json::from_str(&json).unwrap();
+
+        // both of the strings are a match, even though "ABC" was
+        // serialized in its original casing!
+        assert_eq!(restored, CaseInsensitiveString::from("abc"));
+        assert_eq!(
+            hash_of(&restored),
+            hash_of(&CaseInsensitiveString::from("abc"))
+        );
+    }
+
+    #[test]
+    fn round_trip_discards_case_sens_mode() {
+        let original = CaseInsensitiveString::with_case(b"Foo", Case::Sens);
+        let json = serde_json::to_string(&original).unwrap();
+        let restored: CaseInsensitiveString = serde_json::from_str(&json).unwrap();
+
+        // the wire format is a plain string, so the `Case::Sens` mode
+        // doesn't survive the trip: the restored value is Case::Insens...
+        assert_eq!(restored.case(), Case::Insens);
+        // ...and so, unlike `original`, now compares equal to "foo".
+        assert_ne!(original, CaseInsensitiveString::from("foo"));
+        assert_eq!(restored, CaseInsensitiveString::from("foo"));
+    }
+}
@@ -0,0 +1,23 @@
+#[cfg(feature = "unicode")]
+mod tests {
+    use case_insensitive_string::CaseInsensitiveString;
+
+    #[test]
+    fn folds_greek_capital_sigma() {
+        // Σ (capital sigma) lowercases to σ (lowercase sigma).
+        let upper = CaseInsensitiveString::from("ΟΔΥΣΣΕΑΣ");
+        let lower = CaseInsensitiveString::from("οδυσσεασ");
+
+        assert_eq!(upper, lower);
+    }
+
+    #[test]
+    fn folds_turkish_dotted_capital_i() {
+        // İ (dotted capital I) lowercases to "i̇" (i + combining dot above),
+        // not to ASCII's plain "i".
+        let dotted = CaseInsensitiveString::from("İstanbul");
+        let lowercased = CaseInsensitiveString::from("i\u{307}stanbul");
+
+        assert_eq!(dotted, lowercased);
+    }
+}